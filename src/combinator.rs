@@ -0,0 +1,173 @@
+//! Boolean combinators over [`Container`]s.
+//!
+//! [`And`], [`Or`], [`Not`] and [`Xor`] wrap one or two containers and are themselves
+//! `Container`s, delegating `does_contain` to their operands with the matching short-circuit
+//! semantics of `&&`/`||`/`!`. [`ContainerExt`] adds ergonomic builder methods (`and`, `or`,
+//! `not`, `xor`) so containers can be composed without naming the wrapper types directly.
+//! ```rust
+//! use contains::{Container, ContainerExt};
+//!
+//! struct Evens;
+//! impl Container<i32> for Evens {
+//!     fn does_contain(&self, item: &i32) -> bool {
+//!         item % 2 == 0
+//!     }
+//! }
+//!
+//! let in_range_and_even = (0..10).and(Evens);
+//! assert!(in_range_and_even.does_contain(&4));
+//! assert!(!in_range_and_even.does_contain(&3));
+//!
+//! let blacklist = [2, 4, 6];
+//! let allowed = blacklist.not();
+//! assert!(allowed.does_contain(&3));
+//! assert!(!allowed.does_contain(&4));
+//! ```
+
+use crate::Container;
+
+/// A container that is satisfied when both of its operands are: `A::does_contain(item) &&
+/// B::does_contain(item)`, short-circuiting the same way `&&` does.
+pub struct And<A, B>(pub A, pub B);
+
+/// A container that is satisfied when either of its operands is: `A::does_contain(item) ||
+/// B::does_contain(item)`, short-circuiting the same way `||` does.
+pub struct Or<A, B>(pub A, pub B);
+
+/// A container that is satisfied exactly when its operand is not: `!A::does_contain(item)`.
+pub struct Not<A>(pub A);
+
+/// A container that is satisfied when exactly one of its operands is: `A::does_contain(item)
+/// != B::does_contain(item)`.
+pub struct Xor<A, B>(pub A, pub B);
+
+impl<A, B, T> Container<T> for And<A, B>
+where
+    A: Container<T>,
+    B: Container<T>,
+{
+    fn does_contain(&self, item: &T) -> bool {
+        self.0.does_contain(item) && self.1.does_contain(item)
+    }
+}
+
+impl<A, B, T> Container<T> for Or<A, B>
+where
+    A: Container<T>,
+    B: Container<T>,
+{
+    fn does_contain(&self, item: &T) -> bool {
+        self.0.does_contain(item) || self.1.does_contain(item)
+    }
+}
+
+impl<A, T> Container<T> for Not<A>
+where
+    A: Container<T>,
+{
+    fn does_contain(&self, item: &T) -> bool {
+        !self.0.does_contain(item)
+    }
+}
+
+impl<A, B, T> Container<T> for Xor<A, B>
+where
+    A: Container<T>,
+    B: Container<T>,
+{
+    fn does_contain(&self, item: &T) -> bool {
+        self.0.does_contain(item) != self.1.does_contain(item)
+    }
+}
+
+/// Builder methods for composing containers with [`And`], [`Or`], [`Not`] and [`Xor`].
+/// ```rust
+/// use contains::ContainerExt;
+///
+/// let expression = (0..10).and(2..).or(100..101).not();
+/// ```
+///
+/// `and`/`or` are shadowed by `Option`/`Result`'s own inherent `and`/`or` methods, since Rust
+/// prefers an inherent method over a trait method of the same name. Construct [`And`]/[`Or`]
+/// directly instead of going through `ContainerExt` when the left-hand operand is an `Option`
+/// or a `Result`:
+/// ```rust
+/// use contains::{And, Container};
+///
+/// let opt = Some(3);
+/// let expression = And(opt, 0..10);
+/// assert!(expression.does_contain(&3));
+/// ```
+pub trait ContainerExt: Sized {
+    fn and<B>(self, other: B) -> And<Self, B> {
+        And(self, other)
+    }
+
+    fn or<B>(self, other: B) -> Or<Self, B> {
+        Or(self, other)
+    }
+
+    fn not(self) -> Not<Self> {
+        Not(self)
+    }
+
+    fn xor<B>(self, other: B) -> Xor<Self, B> {
+        Xor(self, other)
+    }
+}
+
+impl<C> ContainerExt for C {}
+
+#[test]
+fn test_and() {
+    struct Evens;
+    impl Container<i32> for Evens {
+        fn does_contain(&self, item: &i32) -> bool {
+            item % 2 == 0
+        }
+    }
+
+    let container = (0..10).and(Evens);
+    assert!(container.does_contain(&4));
+    assert!(!container.does_contain(&3));
+    assert!(!container.does_contain(&12));
+}
+
+#[test]
+fn test_or() {
+    let container = (0..5).or(95..100);
+    assert!(container.does_contain(&2));
+    assert!(container.does_contain(&97));
+    assert!(!container.does_contain(&50));
+}
+
+#[test]
+fn test_not() {
+    let blacklist = [1, 2, 3];
+    let container = Not(&blacklist);
+    assert!(container.does_contain(&4));
+    assert!(!container.does_contain(&2));
+}
+
+#[test]
+fn test_xor() {
+    let container = (0..5).xor(3..8);
+    assert!(container.does_contain(&1)); // only in the first range
+    assert!(container.does_contain(&6)); // only in the second range
+    assert!(!container.does_contain(&4)); // in both ranges
+    assert!(!container.does_contain(&9)); // in neither range
+}
+
+#[test]
+fn test_and_or_on_option_and_result_shadowed_by_inherent_methods() {
+    let opt = Some(3);
+    let container = And(opt, 0..10);
+    assert!(container.does_contain(&3));
+    assert!(!container.does_contain(&12));
+
+    let res: Result<i32, ()> = Ok(3);
+    let container = Or(res, 95..100);
+    assert!(container.does_contain(&3));
+    assert!(container.does_contain(&97));
+    assert!(!container.does_contain(&50));
+}
@@ -17,6 +17,19 @@
 //! }
 //! ```
 //!
+//! Some containers aren't limited to holding the exact type you query them with: `Option`,
+//! `Result` and the `Range*` family accept any right-hand side `U` the element type is
+//! `PartialEq`/`PartialOrd` comparable with, `HashSet`/`BTreeSet` accept any `Q` the element
+//! type borrows as, and `Vec<String>` accepts a `&str`, the same way `String: PartialEq<&str>`
+//! lets you compare a `String` to a `&str` directly. `Vec<T>`, `[T; N]`, `&[T]`,
+//! `LinkedList<T>` and `VecDeque<T>` otherwise take only the exact element type `T`.
+//! ```rust
+//! use contains::Container;
+//!
+//! let strings = vec!["a".to_string(), "b".to_string()];
+//! assert!(strings.does_contain(&"a"));
+//! ```
+//!
 //! ## In
 //! The In trait is the Inverse of the Container trait and represents a type that is in
 //! a container. Mainly it reverse the call order by providing the `is_in` method.
@@ -28,6 +41,9 @@
 //! assert!(3.is_in(&range));           // using in
 //! ```
 
+mod combinator;
+pub use combinator::{And, ContainerExt, Not, Or, Xor};
+
 /// ## Container
 /// The Container trait can be used to abstract over
 /// types that can contain items: `Vec<T>`, `&[T]`, `HashMap<T>`, `Option<T>`, ect.
@@ -52,7 +68,33 @@
 ///
 /// assert!([1,2,3,4,5].does_contain(&[3, 4]));
 /// ```
-pub trait Container<T> {
+///
+/// For some containers `does_contain` doesn't require the queried item to be the exact element
+/// type: any `U` the element type is `PartialEq`/`PartialOrd` comparable with works, subject to
+/// the restrictions described below.
+/// ```rust
+/// use contains::Container;
+///
+/// assert!(vec!["a".to_string(), "b".to_string()].does_contain(&"a"));
+/// assert!((0u32..5).does_contain(&3u32));
+/// ```
+/// `Vec<T>`, `[T; N]`, `&[T]`, `LinkedList<T>` and `VecDeque<T>` also implement `Container` for
+/// *subsequence*/*subset* queries (`Container<[T; N1]>`, `Container<&[T]>`,
+/// `Container<Subset<&[U]>>`), where the right-hand side is itself a collection over `T`. A
+/// fully unconstrained `impl<T, U> Container<U> for Vec<T> where T: PartialEq<U>` would overlap
+/// with those, since `U` could unify with `[T; N1]`, `&[T]` or `Subset<&[V]>` — so their plain
+/// element query only goes through the exact element type (`T: PartialEq<T>`), same as
+/// `Vec::contains`. `Vec<String>` is the one exception, with a dedicated `Container<&str>` impl
+/// mirroring `String: PartialEq<&str>` above. `Option`, `Result` and the `Range*` family share no
+/// impl with a subsequence/subset query, so they accept any comparable right-hand side without
+/// that restriction. `HashSet`/`BTreeSet` do have a `Container<Subset<&[Q]>>` impl too, but avoid
+/// the conflict a different way: their plain element query goes through `T: Borrow<Q>`, and
+/// `Subset`'s wrapper type deliberately has no `Eq`/`Hash`/`Ord` impl, so `Q` can never unify
+/// with it.
+pub trait Container<T>
+where
+    T: ?Sized,
+{
     fn does_contain(&self, item: &T) -> bool;
 }
 
@@ -79,6 +121,74 @@ where
     }
 }
 
+/// `IterContainer` gives any iterator a `Container`-like `iter_contains` method.
+///
+/// A plain `Container` impl doesn't fit iterators: `does_contain` takes `&self`, but checking
+/// membership in a one-shot iterator has to consume it. `iter_contains` takes `self` by value
+/// instead, and short-circuits on the first match the same way `Iterator::any` does, so on an
+/// infinite iterator it returns as soon as the item turns up; if the item never turns up, it
+/// never returns.
+/// ```rust
+/// use contains::IterContainer;
+///
+/// assert!((0..).map(|x| x).iter_contains(&3));
+/// ```
+pub trait IterContainer: Iterator + Sized {
+    fn iter_contains(mut self, item: &Self::Item) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        self.any(|x| &x == item)
+    }
+}
+
+impl<I> IterContainer for I where I: Iterator {}
+
+/// The iterator counterpart of [`In`]: lets `item.iter_is_in(iterator)` work for any
+/// `IntoIterator`, consuming it the same way [`IterContainer::iter_contains`] does.
+///
+/// This can't just be another `In` impl, or reuse its `is_in` name: method probing picks a
+/// candidate by receiver type alone, before checking whether the rest of the signature
+/// type-checks, so a second blanket `is_in` over the same receiver type would make every call
+/// ambiguous regardless of the argument. Giving this method its own name sidesteps that.
+/// ```rust
+/// use contains::IterIn;
+///
+/// assert!(6.iter_is_in((1..5).map(|x| x * 2)));
+/// ```
+pub trait IterIn<I>
+where
+    I: IntoIterator,
+{
+    fn iter_is_in(&self, iter: I) -> bool;
+}
+
+impl<I, T> IterIn<I> for T
+where
+    I: IntoIterator<Item = T>,
+    T: PartialEq,
+{
+    fn iter_is_in(&self, iter: I) -> bool {
+        iter.into_iter().any(|x| &x == self)
+    }
+}
+
+/// A reference to a container is itself a container, delegating to the same impl. This lets
+/// combinators (see the `combinator` module) hold a borrowed container, e.g. `Not(&blacklist)`,
+/// instead of forcing it to be cloned or moved in.
+///
+/// `C` is kept `Sized` here (no `?Sized`) because the `&[T]` impls below already cover
+/// unsized slice containers directly; allowing `C` to unify with `[T]` would conflict with
+/// those impls.
+impl<C, T> Container<T> for &C
+where
+    C: Container<T>,
+{
+    fn does_contain(&self, item: &T) -> bool {
+        (**self).does_contain(item)
+    }
+}
+
 impl<T> Container<T> for Vec<T>
 where
     T: PartialEq<T>,
@@ -88,38 +198,46 @@ where
     }
 }
 
-impl<T> Container<T> for Option<T>
+impl Container<&str> for Vec<String> {
+    fn does_contain(&self, item: &&str) -> bool {
+        self.iter().any(|x| x == item)
+    }
+}
+
+impl<T, U> Container<U> for Option<T>
 where
-    T: PartialEq<T>,
+    T: PartialEq<U>,
 {
-    fn does_contain(&self, item: &T) -> bool {
+    fn does_contain(&self, item: &U) -> bool {
         matches!(self, Some(x) if x == item)
     }
 }
 
-impl<T, U> Container<T> for Result<T, U>
+impl<T, E, U> Container<U> for Result<T, E>
 where
-    T: PartialEq<T>,
+    T: PartialEq<U>,
 {
-    fn does_contain(&self, item: &T) -> bool {
+    fn does_contain(&self, item: &U) -> bool {
         matches!(self, Ok(x) if x == item)
     }
 }
 
-impl<T> Container<T> for std::collections::HashSet<T>
+impl<T, Q> Container<Q> for std::collections::HashSet<T>
 where
-    T: Eq + std::hash::Hash,
+    T: std::borrow::Borrow<Q> + Eq + std::hash::Hash,
+    Q: Eq + std::hash::Hash + ?Sized,
 {
-    fn does_contain(&self, item: &T) -> bool {
+    fn does_contain(&self, item: &Q) -> bool {
         self.contains(item)
     }
 }
 
-impl<T> Container<T> for std::collections::BTreeSet<T>
+impl<T, Q> Container<Q> for std::collections::BTreeSet<T>
 where
-    T: Ord,
+    T: std::borrow::Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
 {
-    fn does_contain(&self, item: &T) -> bool {
+    fn does_contain(&self, item: &Q) -> bool {
         self.contains(item)
     }
 }
@@ -129,7 +247,7 @@ where
     T: PartialEq<T>,
 {
     fn does_contain(&self, item: &T) -> bool {
-        self.contains(item)
+        self.iter().any(|x| x == item)
     }
 }
 
@@ -138,7 +256,91 @@ where
     T: PartialEq<T>,
 {
     fn does_contain(&self, item: &T) -> bool {
-        self.contains(item)
+        self.iter().any(|x| x == item)
+    }
+}
+
+/// Wraps a map value so it can be used as the right-hand side of a [`Container`] query that
+/// scans a `HashMap`/`BTreeMap`'s values instead of its keys.
+/// ```rust
+/// use contains::{ByValue, Container};
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert("a", 1);
+/// assert!(map.does_contain(&ByValue(1)));
+/// assert!(!map.does_contain(&ByValue(2)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ByValue<V>(pub V);
+
+/// Wraps a key/value pair so it can be used as the right-hand side of a [`Container`] query
+/// that checks a `HashMap`/`BTreeMap` for that exact key mapping to that exact value.
+/// ```rust
+/// use contains::{Container, Entry};
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert("a", 1);
+/// assert!(map.does_contain(&Entry("a", 1)));
+/// assert!(!map.does_contain(&Entry("a", 2)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Entry<K, V>(pub K, pub V);
+
+impl<K, V> Container<K> for std::collections::HashMap<K, V>
+where
+    K: Eq + std::hash::Hash,
+{
+    fn does_contain(&self, item: &K) -> bool {
+        self.contains_key(item)
+    }
+}
+
+impl<K, V> Container<ByValue<V>> for std::collections::HashMap<K, V>
+where
+    V: PartialEq,
+{
+    fn does_contain(&self, item: &ByValue<V>) -> bool {
+        self.values().any(|v| v == &item.0)
+    }
+}
+
+impl<K, V> Container<Entry<K, V>> for std::collections::HashMap<K, V>
+where
+    K: Eq + std::hash::Hash,
+    V: PartialEq,
+{
+    fn does_contain(&self, item: &Entry<K, V>) -> bool {
+        matches!(self.get(&item.0), Some(v) if v == &item.1)
+    }
+}
+
+impl<K, V> Container<K> for std::collections::BTreeMap<K, V>
+where
+    K: Ord,
+{
+    fn does_contain(&self, item: &K) -> bool {
+        self.contains_key(item)
+    }
+}
+
+impl<K, V> Container<ByValue<V>> for std::collections::BTreeMap<K, V>
+where
+    V: PartialEq,
+{
+    fn does_contain(&self, item: &ByValue<V>) -> bool {
+        self.values().any(|v| v == &item.0)
+    }
+}
+
+impl<K, V> Container<Entry<K, V>> for std::collections::BTreeMap<K, V>
+where
+    K: Ord,
+    V: PartialEq,
+{
+    fn does_contain(&self, item: &Entry<K, V>) -> bool {
+        matches!(self.get(&item.0), Some(v) if v == &item.1)
     }
 }
 
@@ -236,57 +438,179 @@ where
     }
 }
 
-impl<T> Container<T> for std::ops::Range<T>
+impl<T, U> Container<U> for std::ops::Range<T>
 where
-    T: PartialOrd<T>,
+    T: PartialOrd<U>,
 {
-    fn does_contain(&self, item: &T) -> bool {
-        self.contains(item)
+    fn does_contain(&self, item: &U) -> bool {
+        self.start <= *item && self.end > *item
     }
 }
 
-impl<T> Container<T> for std::ops::RangeFrom<T>
+impl<T, U> Container<U> for std::ops::RangeFrom<T>
 where
-    T: PartialOrd<T>,
+    T: PartialOrd<U>,
 {
-    fn does_contain(&self, item: &T) -> bool {
-        self.contains(item)
+    fn does_contain(&self, item: &U) -> bool {
+        self.start <= *item
     }
 }
 
-impl<T> Container<T> for std::ops::RangeTo<T>
+impl<T, U> Container<U> for std::ops::RangeTo<T>
 where
-    T: PartialOrd<T>,
+    T: PartialOrd<U>,
 {
-    fn does_contain(&self, item: &T) -> bool {
-        self.contains(item)
+    fn does_contain(&self, item: &U) -> bool {
+        self.end > *item
     }
 }
 
-impl<T> Container<T> for std::ops::RangeFull
+impl<U> Container<U> for std::ops::RangeFull {
+    fn does_contain(&self, _item: &U) -> bool {
+        true
+    }
+}
+
+impl<T, U> Container<U> for std::ops::RangeInclusive<T>
 where
-    T: PartialOrd<T>,
+    T: PartialOrd<U>,
 {
-    fn does_contain(&self, item: &T) -> bool {
-        std::ops::RangeBounds::contains(self, item)
+    fn does_contain(&self, item: &U) -> bool {
+        *self.start() <= *item && *self.end() >= *item
     }
 }
 
-impl<T> Container<T> for std::ops::RangeInclusive<T>
+impl<T, U> Container<U> for std::ops::RangeToInclusive<T>
 where
-    T: PartialOrd<T>,
+    T: PartialOrd<U>,
 {
-    fn does_contain(&self, item: &T) -> bool {
-        self.contains(item)
+    fn does_contain(&self, item: &U) -> bool {
+        self.end >= *item
     }
 }
 
-impl<T> Container<T> for std::ops::RangeToInclusive<T>
+/// Marks a needle as a *contiguous* subsequence query: `container.does_contain(&Subsequence(s))`
+/// is true when `s` appears as a run of adjacent elements, in order. This is the same
+/// sliding-window matching `[T; N]`/`&[T]`/`Vec<T>` already do for a bare array/slice needle
+/// (e.g. `[1,2,3,4].does_contain(&[2,3])`); `Subsequence` just makes that behavior explicit
+/// so it isn't confused with [`Subset`]. An empty needle is trivially contained; this differs
+/// from a bare empty array/slice needle, which panics because it asks for windows of size 0.
+/// ```rust
+/// use contains::{Container, Subsequence};
+///
+/// let slice = &[1, 2, 3, 4][..];
+/// assert!(slice.does_contain(&Subsequence(&[2, 3][..])));
+/// assert!(!slice.does_contain(&Subsequence(&[2, 4][..])));
+/// assert!(slice.does_contain(&Subsequence(&[][..])));
+/// ```
+pub struct Subsequence<S>(pub S);
+
+/// Marks a needle as an order-independent subset query: `container.does_contain(&Subset(s))`
+/// is true when every element of `s` is present in the container, in any order and regardless
+/// of adjacency. An empty needle is trivially contained.
+/// ```rust
+/// use contains::{Container, Subset};
+/// use std::collections::HashSet;
+///
+/// let set: HashSet<i32> = [1, 2, 3].into_iter().collect();
+/// assert!(set.does_contain(&Subset(&[1, 3][..])));
+/// assert!(!set.does_contain(&Subset(&[1, 4][..])));
+/// assert!(set.does_contain(&Subset(&[][..])));
+/// ```
+pub struct Subset<S>(pub S);
+
+impl<T> Container<Subsequence<&[T]>> for &[T]
 where
-    T: PartialOrd<T>,
+    T: PartialEq,
 {
-    fn does_contain(&self, item: &T) -> bool {
-        self.contains(item)
+    fn does_contain(&self, item: &Subsequence<&[T]>) -> bool {
+        let needle = item.0;
+        needle.is_empty() || self.windows(needle.len()).any(|window| window == needle)
+    }
+}
+
+impl<T, const N: usize> Container<Subsequence<&[T]>> for [T; N]
+where
+    T: PartialEq,
+{
+    fn does_contain(&self, item: &Subsequence<&[T]>) -> bool {
+        let container: &[T] = self;
+        container.does_contain(item)
+    }
+}
+
+impl<T> Container<Subsequence<&[T]>> for Vec<T>
+where
+    T: PartialEq,
+{
+    fn does_contain(&self, item: &Subsequence<&[T]>) -> bool {
+        let container: &[T] = self;
+        container.does_contain(item)
+    }
+}
+
+impl<T, U> Container<Subset<&[U]>> for Vec<T>
+where
+    T: PartialEq<U>,
+{
+    fn does_contain(&self, item: &Subset<&[U]>) -> bool {
+        item.0.iter().all(|needle| self.iter().any(|x| x == needle))
+    }
+}
+
+impl<T, U> Container<Subset<&[U]>> for &[T]
+where
+    T: PartialEq<U>,
+{
+    fn does_contain(&self, item: &Subset<&[U]>) -> bool {
+        item.0.iter().all(|needle| self.iter().any(|x| x == needle))
+    }
+}
+
+impl<T, U, const N: usize> Container<Subset<&[U]>> for [T; N]
+where
+    T: PartialEq<U>,
+{
+    fn does_contain(&self, item: &Subset<&[U]>) -> bool {
+        item.0.iter().all(|needle| self.iter().any(|x| x == needle))
+    }
+}
+
+impl<T, U> Container<Subset<&[U]>> for std::collections::LinkedList<T>
+where
+    T: PartialEq<U>,
+{
+    fn does_contain(&self, item: &Subset<&[U]>) -> bool {
+        item.0.iter().all(|needle| self.iter().any(|x| x == needle))
+    }
+}
+
+impl<T, U> Container<Subset<&[U]>> for std::collections::VecDeque<T>
+where
+    T: PartialEq<U>,
+{
+    fn does_contain(&self, item: &Subset<&[U]>) -> bool {
+        item.0.iter().all(|needle| self.iter().any(|x| x == needle))
+    }
+}
+
+impl<T, Q> Container<Subset<&[Q]>> for std::collections::HashSet<T>
+where
+    T: std::borrow::Borrow<Q> + Eq + std::hash::Hash,
+    Q: Eq + std::hash::Hash,
+{
+    fn does_contain(&self, item: &Subset<&[Q]>) -> bool {
+        item.0.iter().all(|needle| self.contains(needle))
+    }
+}
+
+impl<T, Q> Container<Subset<&[Q]>> for std::collections::BTreeSet<T>
+where
+    T: std::borrow::Borrow<Q> + Ord,
+    Q: Ord,
+{
+    fn does_contain(&self, item: &Subset<&[Q]>) -> bool {
+        item.0.iter().all(|needle| self.contains(needle))
     }
 }
 
@@ -321,3 +645,129 @@ fn test_container() {
         assert!(container.does_contain(&"Hello"));
     }
 }
+
+#[test]
+fn test_container_custom_element_type() {
+    #[derive(PartialEq)]
+    struct Point(i32, i32);
+
+    let array = [Point(1, 2), Point(3, 4)];
+    let slice = &[Point(1, 2), Point(3, 4)] as &[Point];
+    let vec = vec![Point(1, 2), Point(3, 4)];
+    let linked_list: std::collections::LinkedList<Point> =
+        vec![Point(1, 2), Point(3, 4)].into_iter().collect();
+    let vec_deque: std::collections::VecDeque<Point> =
+        vec![Point(1, 2), Point(3, 4)].into_iter().collect();
+
+    assert!(array.does_contain(&Point(1, 2)));
+    assert!(slice.does_contain(&Point(1, 2)));
+    assert!(vec.does_contain(&Point(1, 2)));
+    assert!(!vec.does_contain(&Point(5, 6)));
+    assert!(linked_list.does_contain(&Point(1, 2)));
+    assert!(!linked_list.does_contain(&Point(5, 6)));
+    assert!(vec_deque.does_contain(&Point(1, 2)));
+    assert!(!vec_deque.does_contain(&Point(5, 6)));
+}
+
+#[test]
+fn test_heterogeneous_container() {
+    let strings = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    assert!(strings.does_contain(&"b"));
+    assert!(!strings.does_contain(&"z"));
+
+    let range = 0u32..5u32;
+    assert!(range.does_contain(&3u32));
+
+    let mut set = std::collections::HashSet::new();
+    set.insert("a".to_string());
+    assert!(set.does_contain("a"));
+}
+
+#[test]
+fn test_map_container() {
+    let mut hash_map = std::collections::HashMap::new();
+    hash_map.insert("a", 1);
+    hash_map.insert("b", 2);
+
+    assert!(hash_map.does_contain(&"a"));
+    assert!(!hash_map.does_contain(&"z"));
+    assert!(hash_map.does_contain(&ByValue(2)));
+    assert!(!hash_map.does_contain(&ByValue(3)));
+    assert!(hash_map.does_contain(&Entry("a", 1)));
+    assert!(!hash_map.does_contain(&Entry("a", 2)));
+
+    let mut btree_map = std::collections::BTreeMap::new();
+    btree_map.insert("a", 1);
+    btree_map.insert("b", 2);
+
+    assert!(btree_map.does_contain(&"b"));
+    assert!(btree_map.does_contain(&ByValue(1)));
+    assert!(btree_map.does_contain(&Entry("b", 2)));
+    assert!(!btree_map.does_contain(&Entry("b", 1)));
+}
+
+#[test]
+fn test_iter_contains_short_circuits_on_infinite_iterator() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0);
+    let found = (0..).inspect(|_| calls.set(calls.get() + 1));
+
+    assert!(found.iter_contains(&3));
+    assert_eq!(calls.get(), 4);
+}
+
+#[test]
+fn test_iter_in() {
+    assert!(6.iter_is_in((1..5).map(|x| x * 2)));
+    assert!(!7.iter_is_in((1..5).map(|x| x * 2)));
+}
+
+#[test]
+fn test_subsequence() {
+    let array = [1, 2, 3, 4];
+    let slice = &array[..];
+    let vec = vec![1, 2, 3, 4];
+
+    assert!(array.does_contain(&Subsequence(&[2, 3][..])));
+    assert!(!array.does_contain(&Subsequence(&[2, 4][..])));
+    assert!(slice.does_contain(&Subsequence(&[2, 3][..])));
+    assert!(!slice.does_contain(&Subsequence(&[2, 4][..])));
+    assert!(vec.does_contain(&Subsequence(&[2, 3][..])));
+    assert!(!vec.does_contain(&Subsequence(&[2, 4][..])));
+
+    // An empty needle is trivially a subsequence of anything.
+    assert!(array.does_contain(&Subsequence(&[][..])));
+}
+
+#[test]
+fn test_subset() {
+    let array = [1, 2, 3, 4];
+    let vec = vec![1, 2, 3, 4];
+    let mut hash_set = std::collections::HashSet::new();
+    hash_set.insert(1);
+    hash_set.insert(2);
+    hash_set.insert(3);
+    let mut btree_set = std::collections::BTreeSet::new();
+    btree_set.insert(1);
+    btree_set.insert(2);
+    btree_set.insert(3);
+    let linked_list: std::collections::LinkedList<i32> = vec![1, 2, 3, 4].into_iter().collect();
+    let vec_deque: std::collections::VecDeque<i32> = vec![1, 2, 3, 4].into_iter().collect();
+
+    // Order-independent, unlike Subsequence.
+    assert!(array.does_contain(&Subset(&[2, 4][..])));
+    assert!(!array.does_contain(&Subset(&[2, 5][..])));
+    assert!(vec.does_contain(&Subset(&[4, 1][..])));
+    assert!(hash_set.does_contain(&Subset(&[1, 3][..])));
+    assert!(!hash_set.does_contain(&Subset(&[1, 4][..])));
+    assert!(btree_set.does_contain(&Subset(&[3, 2][..])));
+    assert!(linked_list.does_contain(&Subset(&[4, 1][..])));
+    assert!(!linked_list.does_contain(&Subset(&[4, 5][..])));
+    assert!(vec_deque.does_contain(&Subset(&[4, 1][..])));
+    assert!(!vec_deque.does_contain(&Subset(&[4, 5][..])));
+
+    // An empty needle is trivially a subset of anything.
+    assert!(array.does_contain(&Subset(&[][..])));
+    assert!(hash_set.does_contain(&Subset(&[][..])));
+}